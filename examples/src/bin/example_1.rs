@@ -24,10 +24,11 @@ use embedded_graphics::Drawable;
 use embedded_hal_async::spi::ExclusiveDevice;
 use embedded_hal_async::spi::ExclusiveDeviceError;
 use gpio::{Level, Output};
-use max7219_embassy::{self, MAX7219LedMat};
+use max7219_embassy::{self, SpiInterface, MAX7219LedMat};
 use {defmt_rtt as _, panic_probe as _};
 
-type Display<'d> = MAX7219LedMat<ExclusiveDevice<Spi<'d, SPI1, Async>, Output<'d, PIN_9>>, 256, 4>;
+type Display<'d> =
+    MAX7219LedMat<SpiInterface<ExclusiveDevice<Spi<'d, SPI1, Async>, Output<'d, PIN_9>>>, 4>;
 type DisplayError = Error<ExclusiveDeviceError<embassy_rp::spi::Error, Infallible>>;
 
 async fn test<'a>(display: &mut Display<'a>) -> Result<(), DisplayError> {
@@ -76,7 +77,7 @@ async fn main(_spawner: Spawner) {
     let cs = Output::new(cs, Level::Low);
 
     let spi_dev = ExclusiveDevice::new(spi, cs);
-    let mut display: MAX7219LedMat<_, 256, 4> = MAX7219LedMat::new(spi_dev);
+    let mut display: MAX7219LedMat<_, 4> = MAX7219LedMat::new(SpiInterface::new(spi_dev));
 
     info!("MAX7219 - example 1");
 
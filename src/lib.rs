@@ -11,6 +11,8 @@ pub enum Error<E = ()> {
     Comm(E),
     /// Pin setting error
     Pin(Infallible),
+    /// The chained packet did not fit the transmit buffer (chain too long).
+    Overflow,
 }
 
 pub enum Command {
@@ -67,56 +69,217 @@ pub enum ScanLimit {
     Display0To7 = 0x07,
 }
 
-pub struct MAX7219LedMat<SPI, const BUFLEN: usize, const COUNT: usize> {
+/// Clockwise rotation applied to an individual 8×8 panel to match how it is
+/// physically mounted.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// Physical arrangement of the `COUNT` panels in a chain.
+///
+/// Panels form a `rows` × `cols` grid (so `rows * cols` must equal `COUNT`)
+/// wired one grid row after another. Set `serpentine` when alternate rows run
+/// right-to-left (boustrophedon wiring); those return-row modules are
+/// additionally flipped 180° in-panel, matching how the DIN→DOUT routing forces
+/// them to be mounted. Use `rotation` to compensate for modules mounted turned
+/// from their native orientation.
+#[derive(Clone, Copy)]
+pub struct Layout {
+    pub rows: usize,
+    pub cols: usize,
+    pub rotation: Rotation,
+    pub serpentine: bool,
+}
+
+impl Layout {
+    /// A single row of `cols` panels wired left-to-right with no rotation —
+    /// the implicit layout of the original driver.
+    pub const fn horizontal(cols: usize) -> Self {
+        Layout {
+            rows: 1,
+            cols,
+            rotation: Rotation::Deg0,
+            serpentine: false,
+        }
+    }
+
+    /// Translate a logical `(x, y)` pixel to the physical `(device, row, col)`
+    /// triple — the panel's index in the chain, the in-panel address row, and
+    /// the in-panel column — applying the grid wiring and per-panel rotation.
+    fn remap(&self, x: usize, y: usize) -> (usize, usize, usize) {
+        let mut panel_col = x / 8;
+        let panel_row = y / 8;
+        let lx = x % 8;
+        let ly = y % 8;
+
+        // On a serpentine chain the return rows run right-to-left, and the
+        // DIN→DOUT routing forces those modules to be mounted turned 180° from
+        // the forward rows. Reverse the column order *and* flip the in-panel
+        // orientation so odd rows render the right way up.
+        let flip = self.serpentine && panel_row % 2 == 1;
+        if flip {
+            panel_col = self.cols - 1 - panel_col;
+        }
+        let device = panel_row * self.cols + panel_col;
+
+        let (mut col, mut row) = match self.rotation {
+            Rotation::Deg0 => (lx, ly),
+            Rotation::Deg90 => (7 - ly, lx),
+            Rotation::Deg180 => (7 - lx, 7 - ly),
+            Rotation::Deg270 => (ly, 7 - lx),
+        };
+        if flip {
+            col = 7 - col;
+            row = 7 - row;
+        }
+
+        (device, row, col)
+    }
+}
+
+/// Abstraction over the wire protocol used to talk to a (chain of) MAX7219.
+///
+/// The chip is a plain shift register behind a latch, so any backend able to
+/// clock out bytes and toggle LOAD/CS can drive it: a hardware `SpiDevice`, a
+/// 16-bit-word SPI peripheral, or a bit-banged DIN/CS/CLK GPIO trio on parts
+/// without a spare SPI block. `MAX7219LedMat` is generic over this trait so the
+/// `Error<E>` plumbing lives behind `Self::Error`.
+#[allow(async_fn_in_trait)]
+pub trait Max7219Interface {
+    /// Error surfaced by the underlying bus.
+    type Error;
+
+    /// Write `data` to register `addr`, one data byte per chained device, in a
+    /// single LOAD-framed transaction. `data[0]` is clocked out first and thus
+    /// lands in the device furthest down the chain.
+    async fn write_register(&mut self, addr: u8, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Clock out `bytes` verbatim in one LOAD-framed transaction.
+    async fn write_raw(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// Built-in [`Max7219Interface`] backed by an `embedded_hal_async` `SpiDevice`.
+///
+/// The transmit buffer holds two bytes per device; chains longer than
+/// `MAX_DEVICES` (32) are rejected with [`Error::Overflow`] rather than
+/// silently truncated.
+pub struct SpiInterface<SPI> {
     spi: SPI,
-    framebuffer: [u8; BUFLEN],
 }
 
-impl<SPI, E, const BUFLEN: usize, const COUNT: usize> MAX7219LedMat<SPI, BUFLEN, COUNT>
+impl<SPI> SpiInterface<SPI> {
+    /// Largest chain the fixed transmit buffer can frame (two bytes per device).
+    const MAX_DEVICES: usize = 32;
+
+    pub fn new(spi: SPI) -> Self {
+        SpiInterface { spi }
+    }
+}
+
+impl<SPI, E> Max7219Interface for SpiInterface<SPI>
 where
     SPI: SpiDevice<Error = E>,
     SPI::Bus: SpiBus,
-    [(); 2 * COUNT]: Sized,
 {
-    pub fn new(spi: SPI) -> Self {
-        let max7219 = MAX7219LedMat::<SPI, BUFLEN, COUNT> {
-            spi: spi,
-            framebuffer: [0; BUFLEN],
+    type Error = Error<E>;
+
+    async fn write_register(&mut self, addr: u8, data: &[u8]) -> Result<(), Self::Error> {
+        let mut buffer: Vec<u8, { 2 * Self::MAX_DEVICES }> = Vec::new();
+        for &byte in data {
+            buffer.push(addr).map_err(|_| Error::Overflow)?;
+            buffer.push(byte).map_err(|_| Error::Overflow)?;
+        }
+        self.write_raw(&buffer).await
+    }
+
+    async fn write_raw(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.spi.write(bytes).await.map_err(Error::Comm)
+    }
+}
+
+pub struct MAX7219LedMat<I, const COUNT: usize>
+where
+    [(); 8 * COUNT]: Sized,
+{
+    iface: I,
+    /// Packed 1bpp framebuffer: one byte per address row per panel, MSB first
+    /// so bit `7 - col` of `framebuffer[row * COUNT + device]` is the physical
+    /// pixel for the `(device, row, col)` produced by [`Layout::remap`].
+    framebuffer: [u8; 8 * COUNT],
+    /// One bit per address row (0..8); set when a pixel in that row changed
+    /// since the last flush so `flush()` can skip untouched rows.
+    dirty: u8,
+    /// Physical panel arrangement used to remap logical coordinates.
+    layout: Layout,
+}
+
+impl<I, const COUNT: usize> MAX7219LedMat<I, COUNT>
+where
+    I: Max7219Interface,
+    [(); 8 * COUNT]: Sized,
+{
+    pub fn new(iface: I) -> Self {
+        Self::new_with_layout(iface, Layout::horizontal(COUNT))
+    }
+
+    /// Construct the driver with an explicit panel [`Layout`] for multi-row,
+    /// serpentine, or rotated arrangements.
+    pub fn new_with_layout(iface: I, layout: Layout) -> Self {
+        debug_assert!(
+            layout.rows * layout.cols == COUNT,
+            "Layout grid (rows * cols) must cover exactly COUNT panels"
+        );
+        let max7219 = MAX7219LedMat::<I, COUNT> {
+            iface,
+            framebuffer: [0; 8 * COUNT],
+            dirty: 0,
+            layout,
         };
         max7219
     }
 
-    pub async fn flush(&mut self) -> Result<(), Error<E>> {
+    /// Push a single address row (`0..8`) to every chip in the chain.
+    async fn flush_row(&mut self, addr: usize) -> Result<(), I::Error> {
+        let data = (0..COUNT)
+            .map(|disp| self.framebuffer[addr * COUNT + disp])
+            .collect::<Vec<u8, COUNT>>();
+
+        self.iface.write_register(addr as u8 + 1, &data).await
+    }
+
+    /// Transmit only the rows changed since the previous flush, then clear the
+    /// dirty mask. Skipping an unchanged row is safe because the MAX7219
+    /// latches each register independently.
+    pub async fn flush(&mut self) -> Result<(), I::Error> {
         for addr in 0..8 {
-            let data = (0..COUNT)
-                .rev()
-                .map(|disp| {
-                    let base = (disp * 8) + (addr * (COUNT * 8));
-                    let arr = &self.framebuffer[base..base + 8];
-                    let mut b: u8 = 0;
-                    for i in 0..arr.len() {
-                        b |= arr[i] << (arr.len() - 1 - i);
-                    }
-                    b
-                })
-                .collect::<Vec<u8, COUNT>>();
-
-            let mut buffer: [u8; 2 * COUNT] = [0; 2 * COUNT];
-
-            for i in 0..data.len() {
-                buffer[2 * i] = addr as u8 + 1;
-                buffer[2 * i + 1] = data[data.len() - 1 - i];
+            if self.dirty & (1 << addr) == 0 {
+                continue;
             }
-            self.transmit_raw_data(&buffer).await?
+            self.flush_row(addr).await?;
         }
+        self.dirty = 0;
         Ok(())
     }
 
-    pub async fn transmit_raw_data(&mut self, arr: &[u8]) -> Result<(), Error<E>> {
-        self.spi.write(&arr).await.map_err(Error::Comm)
+    /// Force a full refresh of all 8 rows regardless of the dirty mask, e.g.
+    /// after power-on or a `config_*` change.
+    pub async fn flush_all(&mut self) -> Result<(), I::Error> {
+        for addr in 0..8 {
+            self.flush_row(addr).await?;
+        }
+        self.dirty = 0;
+        Ok(())
     }
 
-    pub async fn config_power_mode(&mut self, mode: Shutdown) -> Result<(), Error<E>> {
+    pub async fn transmit_raw_data(&mut self, arr: &[u8]) -> Result<(), I::Error> {
+        self.iface.write_raw(arr).await
+    }
+
+    pub async fn config_power_mode(&mut self, mode: Shutdown) -> Result<(), I::Error> {
         let data: u8 = match mode {
             Shutdown::NormalOperation => 0x01,
             Shutdown::ShutDownMode => 0x00,
@@ -127,7 +290,7 @@ where
         self.transmit_raw_data(&send_array).await
     }
 
-    pub async fn config_decode_mode(&mut self, mode: DecodeMode) -> Result<(), Error<E>> {
+    pub async fn config_decode_mode(&mut self, mode: DecodeMode) -> Result<(), I::Error> {
         // - Prepare Information to be Sent
         // 8-bit Data/Command Corresponding to No Decode Mode
         let data: u8 = mode as u8;
@@ -138,7 +301,7 @@ where
         self.transmit_raw_data(&send_array).await
     }
 
-    pub async fn config_scan_limit(&mut self, mode: ScanLimit) -> Result<(), Error<E>> {
+    pub async fn config_scan_limit(&mut self, mode: ScanLimit) -> Result<(), I::Error> {
         // - Prepare Information to be Sent
         // 8-bit Data/Command Corresponding to No Decode Mode
         let data: u8 = mode as u8;
@@ -149,7 +312,7 @@ where
         self.transmit_raw_data(&send_array).await
     }
 
-    pub async fn config_intensity(&mut self, mode: Intensity) -> Result<(), Error<E>> {
+    pub async fn config_intensity(&mut self, mode: Intensity) -> Result<(), I::Error> {
         let data: u8 = mode as u8;
         let send_array: [u8; 2] = [Command::Intensity as u8, data];
         // Transmit Data
@@ -157,10 +320,11 @@ where
     }
 
     pub fn clear(&mut self) {
-        self.framebuffer = [0; BUFLEN];
+        self.framebuffer = [0; 8 * COUNT];
+        self.dirty = 0xFF;
     }
 
-    pub async fn init_display(&mut self) -> Result<(), Error<E>> {
+    pub async fn init_display(&mut self) -> Result<(), I::Error> {
         self.config_power_mode(Shutdown::NormalOperation).await?;
         self.config_decode_mode(DecodeMode::NoDecode).await?;
         self.config_scan_limit(ScanLimit::Display0To7).await?;
@@ -168,35 +332,355 @@ where
     }
 }
 
+/// Code B font codes for the non-numeric glyphs the MAX7219 can decode. Digits
+/// `0`..=`9` map to their own value; set bit `0x80` on any code for the point.
+pub mod code_b {
+    pub const DASH: u8 = 0x0A;
+    pub const E: u8 = 0x0B;
+    pub const H: u8 = 0x0C;
+    pub const L: u8 = 0x0D;
+    pub const P: u8 = 0x0E;
+    pub const BLANK: u8 = 0x0F;
+    /// Decimal-point bit, OR-ed into any glyph code.
+    pub const DP: u8 = 0x80;
+}
+
+/// High-level driver for chained 8-digit 7-segment MAX7219 modules.
+///
+/// Unlike [`MAX7219LedMat`], which drives an 8×8 dot matrix through the graphics
+/// framebuffer, this type puts the chip in [`DecodeMode::CodeB70`] so each of
+/// the eight digit registers takes a decoded glyph code instead of a raw
+/// segment pattern. It keeps a shadow of every digit so a single-digit update
+/// can be pushed without disturbing the rest of the chain.
+pub struct Max7219SevenSeg<I, const COUNT: usize>
+where
+    [(); 8 * COUNT]: Sized,
+{
+    iface: I,
+    /// Code B code (plus optional `DP` bit) for each of the `8 * COUNT` digits,
+    /// indexed `digit * COUNT + device`.
+    digits: [u8; 8 * COUNT],
+}
+
+impl<I, const COUNT: usize> Max7219SevenSeg<I, COUNT>
+where
+    I: Max7219Interface,
+    [(); 8 * COUNT]: Sized,
+{
+    pub fn new(iface: I) -> Self {
+        Max7219SevenSeg {
+            iface,
+            digits: [code_b::BLANK; 8 * COUNT],
+        }
+    }
+
+    /// Send `value` to command register `addr` on every chip in the chain, so a
+    /// configuration change reaches the whole chain rather than just the device
+    /// nearest DIN.
+    async fn broadcast(&mut self, addr: u8, value: u8) -> Result<(), I::Error> {
+        let data = [value; COUNT];
+        self.iface.write_register(addr, &data).await
+    }
+
+    pub async fn config_power_mode(&mut self, mode: Shutdown) -> Result<(), I::Error> {
+        let data: u8 = match mode {
+            Shutdown::NormalOperation => 0x01,
+            Shutdown::ShutDownMode => 0x00,
+        };
+        self.broadcast(Command::Shutdown as u8, data).await
+    }
+
+    pub async fn config_decode_mode(&mut self, mode: DecodeMode) -> Result<(), I::Error> {
+        self.broadcast(Command::DecodeMode as u8, mode as u8).await
+    }
+
+    pub async fn config_scan_limit(&mut self, mode: ScanLimit) -> Result<(), I::Error> {
+        self.broadcast(Command::ScanLimit as u8, mode as u8).await
+    }
+
+    pub async fn config_intensity(&mut self, mode: Intensity) -> Result<(), I::Error> {
+        self.broadcast(Command::Intensity as u8, mode as u8).await
+    }
+
+    /// Bring the chip up in Code B decode mode with all eight digits scanned.
+    pub async fn init_display(&mut self) -> Result<(), I::Error> {
+        self.config_power_mode(Shutdown::NormalOperation).await?;
+        self.config_decode_mode(DecodeMode::CodeB70).await?;
+        self.config_scan_limit(ScanLimit::Display0To7).await?;
+        self.config_intensity(Intensity::Ratio3_32).await
+    }
+
+    /// Push a single digit register (`0..8`) to every chip in the chain.
+    async fn flush_digit(&mut self, digit: usize) -> Result<(), I::Error> {
+        let data = (0..COUNT)
+            .map(|device| self.digits[digit * COUNT + device])
+            .collect::<Vec<u8, COUNT>>();
+        self.iface.write_register(digit as u8 + 1, &data).await
+    }
+
+    /// Re-transmit all eight digit registers across the chain.
+    pub async fn flush_all(&mut self) -> Result<(), I::Error> {
+        for digit in 0..8 {
+            self.flush_digit(digit).await?;
+        }
+        Ok(())
+    }
+
+    /// Blank every digit and push the change.
+    pub async fn clear(&mut self) -> Result<(), I::Error> {
+        self.digits = [code_b::BLANK; 8 * COUNT];
+        self.flush_all().await
+    }
+
+    /// Set the shadow glyph for logical digit `pos` (0 = left-most).
+    fn put(&mut self, pos: usize, code: u8) {
+        let device = pos / 8;
+        let digit = pos % 8;
+        self.digits[digit * COUNT + device] = code;
+    }
+
+    /// Write a single Code B glyph at position `pos` (0 = left-most), with an
+    /// optional decimal point, and transmit it immediately. A `pos` past the
+    /// last digit (`>= 8 * COUNT`) is out of range and ignored.
+    pub async fn write_digit(
+        &mut self,
+        pos: usize,
+        value: u8,
+        dp: bool,
+    ) -> Result<(), I::Error> {
+        if pos >= 8 * COUNT {
+            return Ok(());
+        }
+        let code = (value & 0x0F) | if dp { code_b::DP } else { 0 };
+        self.put(pos, code);
+        self.flush_digit(pos % 8).await
+    }
+
+    /// Write a right-aligned decimal integer, blanking leading zeros and
+    /// prefixing a `-` for negative values. Overflowing digits are dropped; if
+    /// the magnitude fills every digit the `-` has nowhere to go and the value
+    /// is shown without its sign.
+    pub async fn write_number(&mut self, value: i32) -> Result<(), I::Error> {
+        let total = 8 * COUNT;
+        self.digits = [code_b::BLANK; 8 * COUNT];
+
+        let neg = value < 0;
+        let mut mag = (value as i64).unsigned_abs();
+        let mut pos = total;
+        loop {
+            pos -= 1;
+            self.put(pos, (mag % 10) as u8);
+            mag /= 10;
+            if mag == 0 || pos == 0 {
+                break;
+            }
+        }
+        if neg && pos > 0 {
+            pos -= 1;
+            self.put(pos, code_b::DASH);
+        }
+        self.flush_all().await
+    }
+
+    /// Write a right-aligned hexadecimal value. Code B can only decode the hex
+    /// glyph `E`; the remaining letters (`A`, `B`, `C`, `D`, `F`) are blanked.
+    pub async fn write_hex(&mut self, value: u32) -> Result<(), I::Error> {
+        let total = 8 * COUNT;
+        self.digits = [code_b::BLANK; 8 * COUNT];
+
+        let mut mag = value;
+        let mut pos = total;
+        loop {
+            pos -= 1;
+            let nibble = (mag & 0x0F) as u8;
+            let code = match nibble {
+                0..=9 => nibble,
+                0x0E => code_b::E,
+                _ => code_b::BLANK,
+            };
+            self.put(pos, code);
+            mag >>= 4;
+            if mag == 0 || pos == 0 {
+                break;
+            }
+        }
+        self.flush_all().await
+    }
+
+    /// Write a left-aligned string using the Code B glyph set (`0`..=`9`, `-`,
+    /// `E`, `H`, `L`, `P`, space). A `.` sets the decimal point of the preceding
+    /// glyph; any other character is rendered blank. Trailing digits are blanked.
+    pub async fn write_str(&mut self, s: &str) -> Result<(), I::Error> {
+        let total = 8 * COUNT;
+        self.digits = [code_b::BLANK; 8 * COUNT];
+
+        let mut pos = 0;
+        for c in s.chars() {
+            if c == '.' {
+                if pos > 0 {
+                    let device = (pos - 1) / 8;
+                    let digit = (pos - 1) % 8;
+                    self.digits[digit * COUNT + device] |= code_b::DP;
+                }
+                continue;
+            }
+            if pos >= total {
+                break;
+            }
+            let code = match c {
+                '0'..='9' => c as u8 - b'0',
+                '-' => code_b::DASH,
+                'E' | 'e' => code_b::E,
+                'H' | 'h' => code_b::H,
+                'L' | 'l' => code_b::L,
+                'P' | 'p' => code_b::P,
+                _ => code_b::BLANK,
+            };
+            self.put(pos, code);
+            pos += 1;
+        }
+        self.flush_all().await
+    }
+}
+
 extern crate embedded_graphics_core;
 use self::embedded_graphics_core::{draw_target::DrawTarget, pixelcolor::BinaryColor, prelude::*};
 
-impl<SPI, const BUFLEN: usize, const COUNT: usize> DrawTarget
-    for MAX7219LedMat<SPI, BUFLEN, COUNT>
+impl<I, const COUNT: usize> DrawTarget for MAX7219LedMat<I, COUNT>
+where
+    [(); 8 * COUNT]: Sized,
 {
     type Color = BinaryColor;
     type Error = core::convert::Infallible;
 
-    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    fn draw_iter<P>(&mut self, pixels: P) -> Result<(), Self::Error>
     where
-        I: IntoIterator<Item = Pixel<Self::Color>>,
+        P: IntoIterator<Item = Pixel<Self::Color>>,
     {
         let bb = self.bounding_box();
         pixels
             .into_iter()
             .filter(|Pixel(pos, _color)| bb.contains(*pos))
             .for_each(|Pixel(pos, color)| {
-                let index: u32 = pos.x as u32 + pos.y as u32 * 8 * (COUNT as u32);
-                self.framebuffer[index as usize] = color.is_on() as u8;
+                let (device, row, col) = self.layout.remap(pos.x as usize, pos.y as usize);
+                let mask = 1 << (7 - col);
+                let index = row * COUNT + device;
+                let old = self.framebuffer[index];
+                if color.is_on() {
+                    self.framebuffer[index] |= mask;
+                } else {
+                    self.framebuffer[index] &= !mask;
+                }
+                if self.framebuffer[index] != old {
+                    self.dirty |= 1 << row;
+                }
             });
         Ok(())
     }
 }
 
-impl<SPI, const BUFLEN: usize, const COUNT: usize> OriginDimensions
-    for MAX7219LedMat<SPI, BUFLEN, COUNT>
+impl<I, const COUNT: usize> OriginDimensions for MAX7219LedMat<I, COUNT>
+where
+    [(); 8 * COUNT]: Sized,
 {
     fn size(&self) -> Size {
-        Size::new(COUNT as u32 * 8, 8)
+        Size::new(self.layout.cols as u32 * 8, self.layout.rows as u32 * 8)
+    }
+}
+
+/// Horizontally scrolling text ticker for messages wider than the physical
+/// chain.
+///
+/// The display itself clips anything past `8*COUNT` columns, so `Marquee` owns
+/// an off-screen framebuffer `VIRT_W` columns wide, packed 1bpp as one byte per
+/// column (bit `y` = the pixel in row `y`) to keep wide tickers cheap on RAM.
+/// Render a `Text` into it once — the struct is a `DrawTarget` `VIRT_W` pixels
+/// wide and 8 tall — then call [`step`](Self::step) on a timer: each call copies
+/// the `8*COUNT`-wide window at the current offset onto the display, flushes it,
+/// and advances the offset by one column, wrapping around at `VIRT_W`. Size
+/// `VIRT_W` as the message width plus the blank inter-message gap you want
+/// between wraps.
+pub struct Marquee<const VIRT_W: usize, const COUNT: usize> {
+    framebuffer: [u8; VIRT_W],
+    x: usize,
+}
+
+impl<const VIRT_W: usize, const COUNT: usize> Marquee<VIRT_W, COUNT> {
+    pub fn new() -> Self {
+        Marquee {
+            framebuffer: [0; VIRT_W],
+            x: 0,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.framebuffer = [0; VIRT_W];
+        self.x = 0;
+    }
+
+    /// Copy the current `8*COUNT`-wide window onto `display`, flush it, then
+    /// advance one column. Drive the call rate from the caller, e.g. with
+    /// `embassy_time::Timer::after`.
+    pub async fn step<I>(
+        &mut self,
+        display: &mut MAX7219LedMat<I, COUNT>,
+    ) -> Result<(), I::Error>
+    where
+        I: Max7219Interface,
+        [(); 8 * COUNT]: Sized,
+    {
+        let width = 8 * COUNT;
+        let x = self.x;
+        let fb = &self.framebuffer;
+        let pixels = (0..width).flat_map(move |col| {
+            (0..8).map(move |y| {
+                let src = (x + col) % VIRT_W;
+                let color = if fb[src] & (1 << y) != 0 {
+                    BinaryColor::On
+                } else {
+                    BinaryColor::Off
+                };
+                Pixel(Point::new(col as i32, y as i32), color)
+            })
+        });
+        let _ = display.draw_iter(pixels);
+        self.x = (self.x + 1) % VIRT_W;
+        display.flush().await
+    }
+}
+
+impl<const VIRT_W: usize, const COUNT: usize> Default for Marquee<VIRT_W, COUNT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const VIRT_W: usize, const COUNT: usize> DrawTarget for Marquee<VIRT_W, COUNT> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<P>(&mut self, pixels: P) -> Result<(), Self::Error>
+    where
+        P: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| bb.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                let mask: u8 = 1 << (pos.y as usize);
+                if color.is_on() {
+                    self.framebuffer[pos.x as usize] |= mask;
+                } else {
+                    self.framebuffer[pos.x as usize] &= !mask;
+                }
+            });
+        Ok(())
+    }
+}
+
+impl<const VIRT_W: usize, const COUNT: usize> OriginDimensions for Marquee<VIRT_W, COUNT> {
+    fn size(&self) -> Size {
+        Size::new(VIRT_W as u32, 8)
     }
 }